@@ -1,36 +1,40 @@
-/// CLI entry point for the swifi speed test tool.
+//! CLI entry point for the swifi speed test tool.
 use {
-        anyhow::Result,
-        clap::Parser,
-        swifi::{CliArgs, ConfigBuilder, ServerList, do_test_config},
+    anyhow::Result,
+    clap::Parser,
+    swifi::{
+        AppConfigBuilder, CliArgs, ServerList, SpeedTest, render_benchmark, render_result,
+        run_monitor,
+    },
 };
 
 fn main() -> Result<()> {
-        let args = CliArgs::parse();
-        let config = ConfigBuilder::from_args(args).build();
+    tracing_subscriber::fmt::init();
 
-        if config.list {
-                let server_list = ServerList::list_servers()?;
-                println!("Available Servers:");
-                println!(
-                        "{:<10} {:<30} {:<40} {:<10}",
-                        "ID", "Sponsor", "Name", "Distance"
-                );
-                println!("{}", "-".repeat(100));
-                for server in server_list.servers {
-                        println!(
-                                "{:<10} {:<30} {:<40} {:<10.2}",
-                                server.id,
-                                server.sponsor,
-                                server.name,
-                                server.distance_km
-                        );
-                }
-                return Ok(());
-        }
+    let args = CliArgs::parse();
+    let config = AppConfigBuilder::from_args(args).build();
+
+    if config.has_list() {
+        let server_list = ServerList::list_servers()?;
+        print!("{}", server_list.format_table());
+        return Ok(());
+    }
 
-        if let Err(e) = do_test_config(&config) {
-                eprintln!("Error: {e}");
+    if let Some(monitor) = config.monitor() {
+        return run_monitor(&config, monitor);
+    }
+
+    if config.is_benchmark() {
+        match SpeedTest::benchmark(&config, || {}) {
+            Ok(report) => println!("{}", render_benchmark(&config, &report)),
+            Err(e) => eprintln!("Error: {e}"),
         }
-        Ok(())
+        return Ok(());
+    }
+
+    match SpeedTest::execute(&config, || {}) {
+        Ok(result) => println!("{}", render_result(&config, &result)),
+        Err(e) => eprintln!("Error: {e}"),
+    }
+    Ok(())
 }
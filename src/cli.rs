@@ -1,4 +1,7 @@
-use {crate::speed_test, clap::Parser};
+use {
+    crate::{output::OutputFormat, speed_test},
+    clap::Parser,
+};
 
 /// A CLI tool for testing wifi download and upload speeds.
 #[derive(Parser, Debug)]
@@ -19,6 +22,106 @@ pub struct CliArgs {
     /// Perform an upload speed test
     #[arg(short, long)]
     pub up: bool,
+
+    /// Run continuously, measuring every `interval` seconds instead of once
+    /// (requires `--csv`)
+    #[arg(long)]
+    pub interval: Option<u64>,
+
+    /// Stop after this many monitor measurements (0 = run indefinitely)
+    #[arg(long, default_value_t = 0)]
+    pub count: u64,
+
+    /// Append each monitor measurement as a CSV row to this file, enabling
+    /// continuous monitoring mode
+    #[arg(long)]
+    pub csv: Option<String>,
+
+    /// Output format for the result
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
+    /// Render speeds in megabytes per second instead of megabits per second
+    #[arg(long)]
+    pub bytes: bool,
+
+    /// Number of parallel threads to use for the test, overriding the
+    /// server-provided default
+    #[arg(long)]
+    pub threads: Option<usize>,
+
+    /// Override the upload/download test duration, in seconds
+    #[arg(long)]
+    pub duration: Option<u64>,
+
+    /// Server ID to exclude from candidate selection (repeatable)
+    #[arg(long = "ignore-server")]
+    pub ignore_server: Vec<u32>,
+
+    /// Benchmark every candidate server instead of stopping at the first
+    /// success, and print a ranked comparison report
+    #[arg(long = "all")]
+    pub all: bool,
+}
+
+/// Tuning knobs for the underlying speed test engine, overriding whatever
+/// `speedtest::get_configuration` would otherwise use.
+#[derive(Debug, Clone, Default)]
+pub struct TuningConfig {
+    threads: Option<usize>,
+    duration_secs: Option<u64>,
+    ignore_servers: Vec<u32>,
+}
+
+impl TuningConfig {
+    /// Returns the configured thread count override, if any.
+    #[must_use]
+    pub const fn threads(&self) -> Option<usize> {
+        self.threads
+    }
+
+    /// Returns the configured test duration override in seconds, if any.
+    #[must_use]
+    pub const fn duration_secs(&self) -> Option<u64> {
+        self.duration_secs
+    }
+
+    /// Returns the server IDs that must never be selected as candidates.
+    #[must_use]
+    pub fn ignore_servers(&self) -> &[u32] {
+        &self.ignore_servers
+    }
+}
+
+/// Default delay between monitor measurements when `--interval` is not set.
+const DEFAULT_MONITOR_INTERVAL_SECS: u64 = 360;
+
+/// Configuration for continuous monitoring mode.
+#[derive(Debug, Clone)]
+pub struct MonitorConfig {
+    seconds_between_runs: u64,
+    count: u64,
+    csv_path: String,
+}
+
+impl MonitorConfig {
+    /// Returns the number of seconds to sleep between measurements.
+    #[must_use]
+    pub const fn interval_secs(&self) -> u64 {
+        self.seconds_between_runs
+    }
+
+    /// Returns the number of measurements to take, or `0` for unlimited.
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the path of the CSV file measurements are appended to.
+    #[must_use]
+    pub fn csv_path(&self) -> &str {
+        &self.csv_path
+    }
 }
 
 /// Configuration for the application, defining parameters for the speed test.
@@ -27,6 +130,11 @@ pub struct AppConfig {
     list: bool,
     server: Option<String>,
     direction: speed_test::Direction,
+    monitor: Option<MonitorConfig>,
+    format: OutputFormat,
+    bytes: bool,
+    tuning: TuningConfig,
+    benchmark: bool,
 }
 
 impl AppConfig {
@@ -47,6 +155,40 @@ impl AppConfig {
     pub const fn direction(&self) -> speed_test::Direction {
         self.direction
     }
+
+    /// Returns the monitor mode configuration, if continuous monitoring was
+    /// requested via `--csv`.
+    #[must_use]
+    pub const fn monitor(&self) -> Option<&MonitorConfig> {
+        self.monitor.as_ref()
+    }
+
+    /// Returns the selected output format (`--format`).
+    #[must_use]
+    pub const fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    /// Returns true if speeds should be rendered in megabytes per second
+    /// (`--bytes`) instead of megabits.
+    #[must_use]
+    pub const fn use_bytes(&self) -> bool {
+        self.bytes
+    }
+
+    /// Returns the speed test engine tuning knobs (`--threads`,
+    /// `--duration`, `--ignore-server`).
+    #[must_use]
+    pub const fn tuning(&self) -> &TuningConfig {
+        &self.tuning
+    }
+
+    /// Returns true if every candidate server should be benchmarked
+    /// (`--all`) instead of stopping at the first success.
+    #[must_use]
+    pub const fn is_benchmark(&self) -> bool {
+        self.benchmark
+    }
 }
 
 /// Builder for creating an `AppConfig` from CLI arguments.
@@ -55,6 +197,15 @@ pub struct AppConfigBuilder {
     server: Option<String>,
     down: bool,
     up: bool,
+    interval: Option<u64>,
+    count: u64,
+    csv: Option<String>,
+    format: OutputFormat,
+    bytes: bool,
+    threads: Option<usize>,
+    duration: Option<u64>,
+    ignore_server: Vec<u32>,
+    all: bool,
 }
 
 impl AppConfigBuilder {
@@ -66,6 +217,15 @@ impl AppConfigBuilder {
             server: args.server,
             down: args.down,
             up: args.up,
+            interval: args.interval,
+            count: args.count,
+            csv: args.csv,
+            format: args.format,
+            bytes: args.bytes,
+            threads: args.threads,
+            duration: args.duration,
+            ignore_server: args.ignore_server,
+            all: args.all,
         }
     }
 
@@ -77,10 +237,80 @@ impl AppConfigBuilder {
             (false, true) => speed_test::Direction::Upload,
             (true, true) | (false, false) => speed_test::Direction::Both,
         };
+        let monitor = self.csv.map(|csv_path| MonitorConfig {
+            seconds_between_runs: self.interval.unwrap_or(DEFAULT_MONITOR_INTERVAL_SECS),
+            count: self.count,
+            csv_path,
+        });
+        let tuning = TuningConfig {
+            threads: self.threads,
+            duration_secs: self.duration,
+            ignore_servers: self.ignore_server,
+        };
         AppConfig {
             list: self.list,
             server: self.server,
             direction,
+            monitor,
+            format: self.format,
+            bytes: self.bytes,
+            tuning,
+            benchmark: self.all,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base_args() -> CliArgs {
+        CliArgs {
+            list: false,
+            server: None,
+            down: false,
+            up: false,
+            interval: None,
+            count: 0,
+            csv: None,
+            format: OutputFormat::Text,
+            bytes: false,
+            threads: None,
+            duration: None,
+            ignore_server: Vec::new(),
+            all: false,
         }
     }
+
+    #[test]
+    fn test_tuning_defaults_to_no_overrides() {
+        let config = AppConfigBuilder::from_args(base_args()).build();
+        assert_eq!(config.tuning().threads(), None);
+        assert_eq!(config.tuning().duration_secs(), None);
+        assert!(config.tuning().ignore_servers().is_empty());
+    }
+
+    #[test]
+    fn test_tuning_carries_threads_duration_and_ignore_list() {
+        let args = CliArgs {
+            threads: Some(8),
+            duration: Some(30),
+            ignore_server: vec![101, 202],
+            ..base_args()
+        };
+        let config = AppConfigBuilder::from_args(args).build();
+        assert_eq!(config.tuning().threads(), Some(8));
+        assert_eq!(config.tuning().duration_secs(), Some(30));
+        assert_eq!(config.tuning().ignore_servers(), [101, 202]);
+    }
+
+    #[test]
+    fn test_benchmark_flag_is_carried_through() {
+        let args = CliArgs {
+            all: true,
+            ..base_args()
+        };
+        let config = AppConfigBuilder::from_args(args).build();
+        assert!(config.is_benchmark());
+    }
 }
\ No newline at end of file
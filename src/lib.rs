@@ -3,11 +3,16 @@
 //! This crate provides CLI tools and libraries for testing `WiFi` download and upload speeds.
 
 mod cli;
+mod monitor;
+mod output;
 mod server;
 mod speed_test;
 
 pub use {
-    cli::{AppConfig, AppConfigBuilder, CliArgs},
+    cli::{AppConfig, AppConfigBuilder, CliArgs, MonitorConfig, TuningConfig},
+    output::{render, render_benchmark, render_result, OutputFormat},
     server::{Server, ServerList},
-    speed_test::{Direction, SpeedMeasurement, SpeedTest, SpeedTestResult},
-};
\ No newline at end of file
+    speed_test::{BenchmarkReport, Direction, SpeedMeasurement, SpeedTest, SpeedTestResult},
+};
+
+pub use monitor::run as run_monitor;
\ No newline at end of file
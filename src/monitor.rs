@@ -0,0 +1,103 @@
+//! Continuous monitoring mode: repeatedly runs a speed test on a fixed
+//! cadence and appends each result to a CSV log, rather than running once
+//! and exiting.
+
+use {
+    crate::{
+        cli::{AppConfig, MonitorConfig},
+        output,
+        speed_test::{SpeedTest, SpeedTestResult},
+    },
+    anyhow::{Context, Result},
+    chrono::Utc,
+    std::{
+        fs::OpenOptions,
+        io::Write,
+        path::Path,
+        thread,
+        time::Duration,
+    },
+    tracing::{error, info},
+};
+
+const CSV_HEADER: &str =
+    "timestamp,server_id,sponsor,name,distance_km,latency_ms,download_mbps,upload_mbps\n";
+
+/// Runs `SpeedTest::execute` on a fixed cadence, appending one CSV row per
+/// successful measurement to `monitor.csv_path()`.
+///
+/// Stops after `monitor.count()` measurements, or runs indefinitely when
+/// `count()` is `0`. A failed measurement is logged and the loop continues
+/// rather than aborting the whole session.
+///
+/// # Errors
+/// Returns an error if the CSV file cannot be created.
+pub fn run(config: &AppConfig, monitor: &MonitorConfig) -> Result<()> {
+    ensure_csv_header(monitor.csv_path())?;
+
+    let mut completed: u64 = 0;
+    loop {
+        match SpeedTest::execute(config, || {}) {
+            Ok(result) => {
+                log_result(&result);
+                if let Err(e) = append_csv_row(monitor.csv_path(), &result) {
+                    error!("Failed to write CSV row: {e}");
+                }
+            }
+            Err(e) => error!("Monitor iteration failed: {e}"),
+        }
+
+        completed += 1;
+        if monitor.count() != 0 && completed >= monitor.count() {
+            break;
+        }
+        thread::sleep(Duration::from_secs(monitor.interval_secs()));
+    }
+
+    Ok(())
+}
+
+fn log_result(result: &SpeedTestResult) {
+    info!(
+        "server {} ({}) - down: {} Mbps, up: {} Mbps",
+        result.server.id,
+        result.server.sponsor,
+        result
+            .download
+            .as_ref()
+            .map_or_else(|| "n/a".to_string(), |m| format!("{:.2}", m.mbps)),
+        result
+            .upload
+            .as_ref()
+            .map_or_else(|| "n/a".to_string(), |m| format!("{:.2}", m.mbps)),
+    );
+}
+
+fn ensure_csv_header(path: &str) -> Result<()> {
+    if Path::new(path).exists() {
+        return Ok(());
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .open(path)
+        .with_context(|| format!("Failed to create CSV file {path}"))?;
+    file.write_all(CSV_HEADER.as_bytes())
+        .with_context(|| format!("Failed to write CSV header to {path}"))
+}
+
+fn append_csv_row(path: &str, result: &SpeedTestResult) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open CSV file {path}"))?;
+
+    writeln!(
+        file,
+        "{},{}",
+        Utc::now().to_rfc3339(),
+        output::render_csv(result, false)
+    )
+    .with_context(|| format!("Failed to append CSV row to {path}"))
+}
@@ -0,0 +1,375 @@
+//! Renders a completed [`SpeedTestResult`] in the format requested via
+//! `--format`, converting to megabytes per second instead of megabits at
+//! display time when `--bytes` is set. The canonical value stored on
+//! [`SpeedMeasurement`] is always Mbps; only rendering converts units.
+
+use {
+    crate::{
+        cli::AppConfig,
+        speed_test::{BenchmarkReport, SpeedMeasurement, SpeedTestResult},
+    },
+    std::fmt::Write as _,
+};
+
+const MBPS_TO_MBPS_PER_BYTE: f64 = 8.0;
+
+/// Selectable output formats for a completed speed test.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text (default).
+    #[default]
+    Text,
+    /// A single JSON object.
+    Json,
+    /// A single comma-separated row.
+    Csv,
+}
+
+/// Renders `result` according to `config`'s `--format` and `--bytes`
+/// settings.
+#[must_use]
+pub fn render_result(config: &AppConfig, result: &SpeedTestResult) -> String {
+    render(result, config.format(), config.use_bytes())
+}
+
+/// Renders `result` as `format`, converting measurements to megabytes per
+/// second instead of megabits when `as_bytes` is set.
+#[must_use]
+pub fn render(result: &SpeedTestResult, format: OutputFormat, as_bytes: bool) -> String {
+    match format {
+        OutputFormat::Text => render_text(result, as_bytes),
+        OutputFormat::Json => render_json(result, as_bytes),
+        OutputFormat::Csv => render_csv(result, as_bytes),
+    }
+}
+
+fn scaled(mbps: f64, as_bytes: bool) -> f64 {
+    if as_bytes {
+        mbps / MBPS_TO_MBPS_PER_BYTE
+    } else {
+        mbps
+    }
+}
+
+fn display_value(measurement: &SpeedMeasurement, as_bytes: bool) -> f64 {
+    scaled(measurement.mbps, as_bytes)
+}
+
+const fn unit_label(as_bytes: bool) -> &'static str {
+    if as_bytes { "MB/s" } else { "Mbps" }
+}
+
+fn render_text(result: &SpeedTestResult, as_bytes: bool) -> String {
+    let unit = unit_label(as_bytes);
+    let mut lines = vec![format!(
+        "Server {} ({}) - {:.1} km",
+        result.server.id, result.server.sponsor, result.server.distance_km
+    )];
+
+    if let Some(latency_ms) = result.latency_ms {
+        lines.push(format!("Latency: {latency_ms:.2} ms"));
+    }
+    if let Some(download) = &result.download {
+        lines.push(format!(
+            "Download: {:.2} {unit}",
+            display_value(download, as_bytes)
+        ));
+    }
+    if let Some(upload) = &result.upload {
+        lines.push(format!(
+            "Upload: {:.2} {unit}",
+            display_value(upload, as_bytes)
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn render_json(result: &SpeedTestResult, as_bytes: bool) -> String {
+    let download = result.download.as_ref().map_or_else(
+        || "null".to_string(),
+        |m| format!("{:.2}", display_value(m, as_bytes)),
+    );
+    let upload = result.upload.as_ref().map_or_else(
+        || "null".to_string(),
+        |m| format!("{:.2}", display_value(m, as_bytes)),
+    );
+    let latency = result
+        .latency_ms
+        .map_or_else(|| "null".to_string(), |l| format!("{l:.2}"));
+
+    format!(
+        "{{\"server_id\":{},\"sponsor\":\"{}\",\"name\":\"{}\",\"distance_km\":{:.2},\"latency_ms\":{latency},\"download\":{download},\"upload\":{upload}}}",
+        result.server.id,
+        escape_json(&result.server.sponsor),
+        escape_json(&result.server.name),
+        result.server.distance_km,
+    )
+}
+
+fn escape_json(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Quotes `value` for use as a CSV field if it contains a comma, quote, or
+/// newline, doubling any embedded quotes, so free-text server metadata
+/// (e.g. a sponsor name like "AT&T Services, Inc.") can't shift columns.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Renders `result` as a single comma-separated CSV row (no trailing
+/// newline). Shared with [`crate::monitor::append_csv_row`] so the
+/// `--format csv` schema and the monitor log schema can't drift apart.
+pub(crate) fn render_csv(result: &SpeedTestResult, as_bytes: bool) -> String {
+    let download = result
+        .download
+        .as_ref()
+        .map_or_else(String::new, |m| format!("{:.2}", display_value(m, as_bytes)));
+    let upload = result
+        .upload
+        .as_ref()
+        .map_or_else(String::new, |m| format!("{:.2}", display_value(m, as_bytes)));
+    let latency = result
+        .latency_ms
+        .map_or_else(String::new, |l| format!("{l:.2}"));
+
+    format!(
+        "{},{},{},{:.2},{latency},{download},{upload}",
+        result.server.id,
+        csv_field(&result.server.sponsor),
+        csv_field(&result.server.name),
+        result.server.distance_km,
+    )
+}
+
+/// Renders `report` according to `config`'s `--format` and `--bytes`
+/// settings.
+#[must_use]
+pub fn render_benchmark(config: &AppConfig, report: &BenchmarkReport) -> String {
+    render_benchmark_as(report, config.format(), config.use_bytes())
+}
+
+/// Renders `report` as `format`, converting measurements to megabytes per
+/// second instead of megabits when `as_bytes` is set.
+#[must_use]
+pub fn render_benchmark_as(report: &BenchmarkReport, format: OutputFormat, as_bytes: bool) -> String {
+    match format {
+        OutputFormat::Text => render_benchmark_table(report, as_bytes),
+        OutputFormat::Json => render_benchmark_json(report, as_bytes),
+        OutputFormat::Csv => render_benchmark_csv(report, as_bytes),
+    }
+}
+
+/// Ranks results by whichever speed was actually measured (see
+/// [`SpeedTestResult::measured_mbps`]), highest first, so an upload-only
+/// (`--up`) benchmark isn't ranked by an all-zero download column.
+fn ranked_by_measured_speed(report: &BenchmarkReport) -> Vec<&SpeedTestResult> {
+    let mut ranked: Vec<&SpeedTestResult> = report.results.iter().collect();
+    ranked.sort_by(|a, b| {
+        b.measured_mbps()
+            .partial_cmp(&a.measured_mbps())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    ranked
+}
+
+fn render_benchmark_table(report: &BenchmarkReport, as_bytes: bool) -> String {
+    let unit = unit_label(as_bytes);
+    let mut output = String::new();
+    output.push_str("Benchmark Results:\n");
+    writeln!(
+        output,
+        "{:<6} {:<10} {:<30} {:<15} {:<15}",
+        "Rank",
+        "ID",
+        "Sponsor",
+        format!("Download ({unit})"),
+        format!("Upload ({unit})")
+    )
+    .ok();
+    writeln!(output, "{}", "-".repeat(100)).ok();
+
+    for (rank, result) in ranked_by_measured_speed(report).into_iter().enumerate() {
+        writeln!(
+            output,
+            "{:<6} {:<10} {:<30} {:<15} {:<15}",
+            rank + 1,
+            result.server.id,
+            result.server.sponsor,
+            result
+                .download
+                .as_ref()
+                .map_or_else(|| "n/a".to_string(), |m| format!("{:.2}", display_value(m, as_bytes))),
+            result
+                .upload
+                .as_ref()
+                .map_or_else(|| "n/a".to_string(), |m| format!("{:.2}", display_value(m, as_bytes))),
+        )
+        .ok();
+    }
+
+    if let Some((min, median, max, mean)) = report.download_stats() {
+        writeln!(
+            output,
+            "\nDownload ({unit}) - min: {:.2}, median: {:.2}, max: {:.2}, mean: {:.2}",
+            scaled(min, as_bytes),
+            scaled(median, as_bytes),
+            scaled(max, as_bytes),
+            scaled(mean, as_bytes)
+        )
+        .ok();
+    }
+    if let Some((min, median, max, mean)) = report.upload_stats() {
+        writeln!(
+            output,
+            "Upload ({unit}) - min: {:.2}, median: {:.2}, max: {:.2}, mean: {:.2}",
+            scaled(min, as_bytes),
+            scaled(median, as_bytes),
+            scaled(max, as_bytes),
+            scaled(mean, as_bytes)
+        )
+        .ok();
+    }
+    if let Some(fastest) = report.fastest() {
+        writeln!(
+            output,
+            "Fastest server: {} ({})",
+            fastest.server.id, fastest.server.sponsor
+        )
+        .ok();
+    }
+
+    output
+}
+
+fn render_benchmark_json(report: &BenchmarkReport, as_bytes: bool) -> String {
+    let entries: Vec<String> = report
+        .results
+        .iter()
+        .map(|r| render_json(r, as_bytes))
+        .collect();
+    let fastest_id = report
+        .fastest()
+        .map_or_else(|| "null".to_string(), |r| r.server.id.to_string());
+
+    format!(
+        "{{\"results\":[{}],\"fastest_server_id\":{fastest_id}}}",
+        entries.join(",")
+    )
+}
+
+fn render_benchmark_csv(report: &BenchmarkReport, as_bytes: bool) -> String {
+    let mut rows = vec!["server_id,sponsor,name,distance_km,latency_ms,download,upload".to_string()];
+    rows.extend(
+        ranked_by_measured_speed(report)
+            .into_iter()
+            .map(|r| render_csv(r, as_bytes)),
+    );
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::server::Server,
+        std::sync::Arc,
+    };
+
+    fn sample_result() -> SpeedTestResult {
+        SpeedTestResult {
+            server: Server {
+                id: 7,
+                sponsor: Arc::from("Sponsor"),
+                name: Arc::from("Name"),
+                distance_km: 12.3,
+                url: "http://testserver.com".to_string(),
+                latency_ms: Some(15.0),
+            },
+            download: Some(SpeedMeasurement { mbps: 80.0 }),
+            upload: Some(SpeedMeasurement { mbps: 16.0 }),
+            latency_ms: Some(15.0),
+        }
+    }
+
+    #[test]
+    fn test_render_json_mbps() {
+        let rendered = render(&sample_result(), OutputFormat::Json, false);
+        assert!(rendered.contains("\"download\":80.00"));
+        assert!(rendered.contains("\"upload\":16.00"));
+    }
+
+    #[test]
+    fn test_render_bytes_conversion() {
+        let rendered = render(&sample_result(), OutputFormat::Text, true);
+        assert!(rendered.contains("Download: 10.00 MB/s"));
+        assert!(rendered.contains("Upload: 2.00 MB/s"));
+    }
+
+    #[test]
+    fn test_render_csv_row() {
+        let rendered = render(&sample_result(), OutputFormat::Csv, false);
+        assert_eq!(rendered, "7,Sponsor,Name,12.30,15.00,80.00,16.00");
+    }
+
+    #[test]
+    fn test_render_csv_quotes_sponsor_containing_a_comma() {
+        let mut result = sample_result();
+        result.server.sponsor = Arc::from("AT&T Services, Inc.");
+
+        let rendered = render(&result, OutputFormat::Csv, false);
+        assert_eq!(
+            rendered,
+            "7,\"AT&T Services, Inc.\",Name,12.30,15.00,80.00,16.00"
+        );
+    }
+
+    #[test]
+    fn test_render_benchmark_ranks_by_download_and_reports_fastest() {
+        let mut slower = sample_result();
+        slower.server.id = 8;
+        slower.download = Some(SpeedMeasurement { mbps: 40.0 });
+
+        let report = BenchmarkReport {
+            results: vec![slower, sample_result()],
+        };
+
+        let rendered = render_benchmark_as(&report, OutputFormat::Text, false);
+        assert!(rendered.contains("Fastest server: 7 (Sponsor)"));
+        assert!(rendered.contains("Download (Mbps) - min: 40.00, median: 60.00, max: 80.00, mean: 60.00"));
+
+        let ranked_csv = render_benchmark_csv(&report, false);
+        let rank_of_id_7 = ranked_csv.find("7,Sponsor").unwrap();
+        let rank_of_id_8 = ranked_csv.find("8,Sponsor").unwrap();
+        assert!(rank_of_id_7 < rank_of_id_8);
+    }
+
+    #[test]
+    fn test_render_benchmark_ranks_by_upload_when_download_is_absent() {
+        let mut slower = sample_result();
+        slower.server.id = 8;
+        slower.download = None;
+        slower.upload = Some(SpeedMeasurement { mbps: 5.0 });
+
+        let mut faster = sample_result();
+        faster.download = None;
+        faster.upload = Some(SpeedMeasurement { mbps: 20.0 });
+
+        let report = BenchmarkReport {
+            results: vec![slower, faster],
+        };
+
+        let rendered = render_benchmark_as(&report, OutputFormat::Text, false);
+        assert!(rendered.contains("Fastest server: 7 (Sponsor)"));
+
+        let ranked_csv = render_benchmark_csv(&report, false);
+        let rank_of_id_7 = ranked_csv.find("7,Sponsor").unwrap();
+        let rank_of_id_8 = ranked_csv.find("8,Sponsor").unwrap();
+        assert!(rank_of_id_7 < rank_of_id_8);
+    }
+}
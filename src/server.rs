@@ -1,13 +1,20 @@
 use {
     anyhow::Result,
     speedtest_rs::{distance::EarthLocation, speedtest},
-    std::{fmt::Write as _, sync::Arc},
+    std::{
+        fmt::Write as _,
+        sync::Arc,
+        time::{Duration, Instant},
+    },
+    tracing::warn,
 };
 
 const MAX_SPONSOR_LENGTH: usize = 20;
 const MAX_NAME_LENGTH: usize = 20;
 const TOP_X_NUM_SERVERS: usize = 10;
 const DEFAULT_SERVER_COUNT: usize = 3;
+const LATENCY_PROBE_COUNT: usize = 3;
+const LATENCY_PROBE_TIMEOUT_SECS: u64 = 5;
 
 /// Represents a speed test server with its metadata.
 #[derive(Debug, Clone)]
@@ -22,6 +29,9 @@ pub struct Server {
     pub distance_km: f32,
     /// The URL used for testing.
     pub url: String,
+    /// Measured round-trip latency in milliseconds, if this server has been
+    /// probed via [`Server::measure_latency`].
+    pub latency_ms: Option<f32>,
 }
 
 impl Server {
@@ -39,6 +49,44 @@ impl Server {
             location: EarthLocation::default(),
         }
     }
+
+    /// Probes this server's latency by issuing three sequential HTTP GETs
+    /// to its `latency.txt` endpoint and returning the fastest round trip,
+    /// in milliseconds.
+    ///
+    /// Each probe is bounded by [`LATENCY_PROBE_TIMEOUT_SECS`] so a
+    /// firewalled/stalled candidate can't hang the whole CLI before any
+    /// actual test runs.
+    ///
+    /// # Errors
+    /// Returns an error if every probe request fails.
+    pub fn measure_latency(&self) -> Result<f32> {
+        let latency_url = latency_url(&self.url);
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(LATENCY_PROBE_TIMEOUT_SECS))
+            .build()
+            .map_err(|e| anyhow::anyhow!("Failed to build HTTP client: {e}"))?;
+
+        let mut fastest: Option<f32> = None;
+        for _ in 0..LATENCY_PROBE_COUNT {
+            let start = Instant::now();
+            if client.get(&latency_url).send().is_ok() {
+                let elapsed_ms = start.elapsed().as_secs_f32() * 1000.0;
+                fastest = Some(fastest.map_or(elapsed_ms, |best: f32| best.min(elapsed_ms)));
+            }
+        }
+
+        fastest.ok_or_else(|| anyhow::anyhow!("All latency probes failed for server {}", self.id))
+    }
+}
+
+/// Derives the `latency.txt` URL that sits alongside a server's test URL.
+fn latency_url(server_url: &str) -> String {
+    let path_start = server_url.find("://").map_or(0, |idx| idx + 3);
+    match server_url[path_start..].rfind('/') {
+        Some(idx) => format!("{}/latency.txt", &server_url[..path_start + idx]),
+        None => format!("{server_url}/latency.txt"),
+    }
 }
 
 impl std::fmt::Display for Server {
@@ -97,7 +145,7 @@ impl ServerList {
 
     /// # Errors
     /// Returns an error if unable to retrieve or parse the server list from the speedtest API.
-    pub fn get_servers(num: usize) -> Result<Vec<Server>> {
+    pub fn get_servers(num: usize, ignore: &[u32]) -> Result<Vec<Server>> {
         let config = speedtest::get_configuration()
             .map_err(|e| anyhow::anyhow!("Failed to retrieve speedtest configuration: {e:?}"))?;
 
@@ -110,6 +158,7 @@ impl ServerList {
 
         let result: Vec<Server> = sorted_servers
             .iter()
+            .filter(|s| !ignore.contains(&s.id))
             .take(num)
             .map(|s| Server {
                 id: s.id,
@@ -117,6 +166,7 @@ impl ServerList {
                 name: Arc::from(s.name.as_str()),
                 distance_km: s.distance.unwrap_or(0.0),
                 url: s.url.clone(),
+                latency_ms: None,
             })
             .collect();
 
@@ -126,7 +176,7 @@ impl ServerList {
     /// # Errors
     /// Will return `Err` if unable to retrieve server list.
     pub fn get_top_x(x: usize) -> Result<Self> {
-        let servers = Self::get_servers(x)?;
+        let servers = Self::get_servers(x, &[])?;
         Ok(Self { servers })
     }
 
@@ -138,12 +188,12 @@ impl ServerList {
 
     /// # Errors
     /// Will return `Err` if server ID is invalid or not found.
-    pub fn select_server(server_id: Option<String>) -> Result<Vec<Server>> {
+    pub fn select_server(server_id: Option<String>, ignore: &[u32]) -> Result<Vec<Server>> {
         if let Some(id_str) = server_id {
             let id = id_str
                 .parse::<u32>()
                 .map_err(|_| anyhow::anyhow!("Server ID must be a valid number"))?;
-            let all_servers = Self::get_servers(TOP_X_NUM_SERVERS)?;
+            let all_servers = Self::get_servers(TOP_X_NUM_SERVERS, ignore)?;
             let filtered: Vec<Server> = all_servers.into_iter().filter(|s| s.id == id).collect();
 
             if filtered.is_empty() {
@@ -151,9 +201,39 @@ impl ServerList {
             }
             Ok(filtered)
         } else {
-            Self::get_servers(DEFAULT_SERVER_COUNT)
+            let candidates = Self::get_servers(TOP_X_NUM_SERVERS, ignore)?;
+            let mut ranked = Self::rank_by_latency(candidates);
+            ranked.truncate(DEFAULT_SERVER_COUNT);
+            Ok(ranked)
         }
     }
+
+    /// Measures latency for each candidate, discards the ones that fail to
+    /// respond, and returns the survivors ordered ascending by latency so
+    /// the genuinely closest-in-RTT server comes first.
+    fn rank_by_latency(candidates: Vec<Server>) -> Vec<Server> {
+        let mut measured: Vec<Server> = candidates
+            .into_iter()
+            .filter_map(|mut server| match server.measure_latency() {
+                Ok(latency_ms) => {
+                    server.latency_ms = Some(latency_ms);
+                    Some(server)
+                }
+                Err(e) => {
+                    warn!("Discarding server {}: {e}", server.id);
+                    None
+                }
+            })
+            .collect();
+
+        measured.sort_by(|a, b| {
+            a.latency_ms
+                .partial_cmp(&b.latency_ms)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        measured
+    }
 }
 
 #[cfg(test)]
@@ -174,6 +254,7 @@ mod tests {
             name: Arc::from("Server Name"),
             distance_km: 100.5,
             url: "http://testserver.com".to_string(),
+            latency_ms: None,
         };
         let display_str = server.to_string();
         assert!(display_str.contains("Server 1"));
@@ -182,7 +263,7 @@ mod tests {
 
     #[test]
     fn test_select_server_invalid_id() {
-        let result = ServerList::select_server(Some("not_a_number".to_string()));
+        let result = ServerList::select_server(Some("not_a_number".to_string()), &[]);
         assert!(result.is_err(), "Expected error for invalid server ID");
         if let Err(err) = result {
             assert!(err.to_string().contains("valid number"));
@@ -191,7 +272,7 @@ mod tests {
 
     #[test]
     fn test_select_server_valid_parse() {
-        let result = ServerList::select_server(Some("12345".to_string()));
+        let result = ServerList::select_server(Some("12345".to_string()), &[]);
         if let Err(e) = result {
             let msg = e.to_string();
             assert!(!msg.contains("valid number"));
@@ -206,6 +287,7 @@ mod tests {
             name: Arc::from("Test Name"),
             distance_km: 50.0,
             url: "http://testserver.com".to_string(),
+            latency_ms: None,
         };
         let speedtest_server = server.to_speedtest_server();
         assert_eq!(speedtest_server.id, 42);
@@ -213,4 +295,16 @@ mod tests {
         assert_eq!(speedtest_server.name, "Test Name");
         assert_eq!(speedtest_server.distance, Some(50.0));
     }
+
+    #[test]
+    fn test_latency_url() {
+        assert_eq!(
+            latency_url("http://speedtest.example.com/speedtest/upload.php"),
+            "http://speedtest.example.com/speedtest/latency.txt"
+        );
+        assert_eq!(
+            latency_url("http://speedtest.example.com"),
+            "http://speedtest.example.com/latency.txt"
+        );
+    }
 }
\ No newline at end of file
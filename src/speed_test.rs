@@ -1,10 +1,11 @@
 use {
     crate::{
-        cli::AppConfig,
+        cli::{AppConfig, TuningConfig},
         server::{Server, ServerList},
     },
     anyhow::{Result, bail},
     speedtest_rs::{speedtest, speedtest_config::SpeedTestConfig},
+    std::time::Duration,
     tracing::{error, info, warn},
 };
 
@@ -38,6 +39,85 @@ pub struct SpeedTestResult {
     pub download: Option<SpeedMeasurement>,
     /// Result of the upload test, if performed.
     pub upload: Option<SpeedMeasurement>,
+    /// The chosen server's measured latency in milliseconds, if it was
+    /// probed during server selection.
+    pub latency_ms: Option<f32>,
+}
+
+impl SpeedTestResult {
+    /// Returns the speed actually measured for this result: download when
+    /// present, otherwise upload (e.g. for an upload-only `--up` run), or
+    /// `None` if neither direction was tested.
+    #[must_use]
+    pub fn measured_mbps(&self) -> Option<f64> {
+        self.download
+            .as_ref()
+            .or(self.upload.as_ref())
+            .map(|m| m.mbps)
+    }
+}
+
+/// Aggregated results from benchmarking every candidate server, rather than
+/// stopping at the first success.
+#[derive(Debug, Clone)]
+pub struct BenchmarkReport {
+    /// One result per server that completed successfully.
+    pub results: Vec<SpeedTestResult>,
+}
+
+impl BenchmarkReport {
+    /// Returns the result with the highest measured speed (see
+    /// [`SpeedTestResult::measured_mbps`]), or `None` if no result measured
+    /// either direction.
+    #[must_use]
+    pub fn fastest(&self) -> Option<&SpeedTestResult> {
+        self.results
+            .iter()
+            .filter(|r| r.measured_mbps().is_some())
+            .max_by(|a, b| {
+                a.measured_mbps()
+                    .partial_cmp(&b.measured_mbps())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+    }
+
+    /// Returns (min, median, max, mean) download Mbps across all results,
+    /// or `None` if no result contains a download measurement.
+    #[must_use]
+    pub fn download_stats(&self) -> Option<(f64, f64, f64, f64)> {
+        Self::stats(&self.mbps_values(|r| r.download.as_ref()))
+    }
+
+    /// Returns (min, median, max, mean) upload Mbps across all results, or
+    /// `None` if no result contains an upload measurement.
+    #[must_use]
+    pub fn upload_stats(&self) -> Option<(f64, f64, f64, f64)> {
+        Self::stats(&self.mbps_values(|r| r.upload.as_ref()))
+    }
+
+    fn mbps_values(&self, pick: impl Fn(&SpeedTestResult) -> Option<&SpeedMeasurement>) -> Vec<f64> {
+        self.results.iter().filter_map(|r| pick(r).map(|m| m.mbps)).collect()
+    }
+
+    fn stats(values: &[f64]) -> Option<(f64, f64, f64, f64)> {
+        if values.is_empty() {
+            return None;
+        }
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let min = sorted[0];
+        let max = sorted[sorted.len() - 1];
+        let mean = sorted.iter().sum::<f64>() / sorted.len() as f64;
+        let mid = sorted.len() / 2;
+        let median = if sorted.len() % 2 == 0 {
+            (sorted[mid - 1] + sorted[mid]) / 2.0
+        } else {
+            sorted[mid]
+        };
+
+        Some((min, median, max, mean))
+    }
 }
 
 /// A speed test executor.
@@ -83,6 +163,9 @@ impl SpeedTest {
     {
         let server = self.server.to_speedtest_server();
         info!("Testing connection on server: {} ({})", server.id, server.name);
+        if let Some(latency_ms) = self.server.latency_ms {
+            info!("Latency: {latency_ms:.2} ms");
+        }
 
         let download = if self.should_download() {
             Some(self.run_download_test(config, progress_callback)?)
@@ -100,6 +183,7 @@ impl SpeedTest {
             server: self.server.clone(),
             download,
             upload,
+            latency_ms: self.server.latency_ms,
         })
     }
 
@@ -125,9 +209,9 @@ impl SpeedTest {
         )
         .map_err(|e| anyhow::anyhow!("Download speed test failed: {e:?}"))?;
 
-        Ok(SpeedMeasurement { 
-            mbps: Self::calculate_mbps(measurement.bps_f64()) 
-        })
+        let mbps = Self::calculate_mbps(measurement.bps_f64());
+        info!("Download Speed: {mbps:.2} Mbps");
+        Ok(SpeedMeasurement { mbps })
     }
 
     fn run_upload_test<F>(&self, config: &SpeedTestConfig, progress_callback: F) -> Result<SpeedMeasurement> 
@@ -143,10 +227,10 @@ impl SpeedTest {
             config,
         )
         .map_err(|e| anyhow::anyhow!("Upload speed test failed: {e:?}"))?;
-        
-        Ok(SpeedMeasurement { 
-            mbps: Self::calculate_mbps(measurement.bps_f64()) 
-        })
+
+        let mbps = Self::calculate_mbps(measurement.bps_f64());
+        info!("Upload Speed: {mbps:.2} Mbps");
+        Ok(SpeedMeasurement { mbps })
     }
 
     const fn calculate_mbps(bps: f64) -> f64 {
@@ -157,20 +241,15 @@ impl SpeedTest {
     ///
     /// # Errors
     /// Returns an error if no servers are available or all attempts fail.
-    pub fn execute<F>(config: &AppConfig, progress_callback: F) -> Result<SpeedTestResult> 
-    where 
+    pub fn execute<F>(config: &AppConfig, progress_callback: F) -> Result<SpeedTestResult>
+    where
         F: Fn() + Send + Copy + Sync + 'static,
     {
-        let servers = ServerList::select_server(config.server_id().cloned())?;
-
-        if servers.is_empty() {
-            error!("No servers available for testing");
-            bail!("No servers available for testing");
-        }
+        let (servers, mut speed_config) = Self::prepare(config)?;
 
         for (index, server) in servers.iter().enumerate() {
             let test = Self::new(server.clone(), config.direction());
-            match test.run(progress_callback) {
+            match test.run_test(&mut speed_config, progress_callback) {
                 Ok(result) => return Ok(result),
                 Err(e) => {
                     error!("Error with server {}: {}", server.id, e);
@@ -186,4 +265,160 @@ impl SpeedTest {
         }
         bail!("Unexpected error: loop finished without success or bail")
     }
+
+    /// Runs the speed test against every candidate server returned by
+    /// `ServerList::select_server`, instead of stopping at the first
+    /// success. A failing candidate is logged and skipped.
+    ///
+    /// # Errors
+    /// Returns an error if no servers are available or every attempt fails.
+    pub fn benchmark<F>(config: &AppConfig, progress_callback: F) -> Result<BenchmarkReport>
+    where
+        F: Fn() + Send + Copy + Sync + 'static,
+    {
+        let (servers, mut speed_config) = Self::prepare(config)?;
+
+        let mut results = Vec::with_capacity(servers.len());
+        for server in &servers {
+            let test = Self::new(server.clone(), config.direction());
+            match test.run_test(&mut speed_config, progress_callback) {
+                Ok(result) => results.push(result),
+                Err(e) => error!("Error with server {}: {}", server.id, e),
+            }
+        }
+
+        if results.is_empty() {
+            bail!("All attempts failed. Please check your connection.");
+        }
+
+        Ok(BenchmarkReport { results })
+    }
+
+    /// Selects candidate servers and builds a tuned `speedtest` engine
+    /// configuration, shared by [`Self::execute`] and [`Self::benchmark`] so
+    /// the two entry points can't silently diverge.
+    ///
+    /// # Errors
+    /// Returns an error if no servers are available or the speedtest
+    /// configuration can't be retrieved.
+    fn prepare(config: &AppConfig) -> Result<(Vec<Server>, SpeedTestConfig)> {
+        let servers = ServerList::select_server(
+            config.server_id().cloned(),
+            config.tuning().ignore_servers(),
+        )?;
+
+        if servers.is_empty() {
+            error!("No servers available for testing");
+            bail!("No servers available for testing");
+        }
+
+        let mut speed_config = speedtest::get_configuration()
+            .map_err(|e| anyhow::anyhow!("Failed to retrieve speedtest configuration: {e:?}"))?;
+        Self::apply_tuning(&mut speed_config, config.tuning());
+
+        Ok((servers, speed_config))
+    }
+
+    /// Overrides the server-provided defaults on `speed_config` with any
+    /// tuning knobs the user supplied via `--threads`, `--duration`, and
+    /// `--ignore-server`.
+    fn apply_tuning(speed_config: &mut SpeedTestConfig, tuning: &TuningConfig) {
+        if let Some(threads) = tuning.threads() {
+            speed_config.threads = threads;
+        }
+        if let Some(duration_secs) = tuning.duration_secs() {
+            speed_config.length = Duration::from_secs(duration_secs);
+        }
+        speed_config.ignore_servers = tuning.ignore_servers().to_vec();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::server::Server, std::sync::Arc};
+
+    fn result_with(id: u32, download: Option<f64>, upload: Option<f64>) -> SpeedTestResult {
+        SpeedTestResult {
+            server: Server {
+                id,
+                sponsor: Arc::from("Sponsor"),
+                name: Arc::from("Name"),
+                distance_km: 1.0,
+                url: "http://testserver.com".to_string(),
+                latency_ms: None,
+            },
+            download: download.map(|mbps| SpeedMeasurement { mbps }),
+            upload: upload.map(|mbps| SpeedMeasurement { mbps }),
+            latency_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_measured_mbps_prefers_download_then_falls_back_to_upload() {
+        let both = result_with(1, Some(50.0), Some(10.0));
+        assert_eq!(both.measured_mbps(), Some(50.0));
+
+        let upload_only = result_with(2, None, Some(10.0));
+        assert_eq!(upload_only.measured_mbps(), Some(10.0));
+
+        let neither = result_with(3, None, None);
+        assert_eq!(neither.measured_mbps(), None);
+    }
+
+    #[test]
+    fn test_fastest_picks_highest_measured_speed_across_mixed_directions() {
+        let report = BenchmarkReport {
+            results: vec![
+                result_with(1, Some(20.0), None),
+                result_with(2, None, Some(30.0)),
+                result_with(3, Some(15.0), None),
+            ],
+        };
+
+        assert_eq!(report.fastest().map(|r| r.server.id), Some(2));
+    }
+
+    #[test]
+    fn test_fastest_returns_none_when_no_result_measured_either_direction() {
+        let report = BenchmarkReport {
+            results: vec![result_with(1, None, None)],
+        };
+
+        assert!(report.fastest().is_none());
+    }
+
+    #[test]
+    fn test_stats_computes_median_for_even_and_odd_length_inputs() {
+        let odd = BenchmarkReport {
+            results: vec![
+                result_with(1, Some(10.0), None),
+                result_with(2, Some(30.0), None),
+                result_with(3, Some(20.0), None),
+            ],
+        };
+        assert_eq!(odd.download_stats(), Some((10.0, 20.0, 30.0, 20.0)));
+
+        let even = BenchmarkReport {
+            results: vec![
+                result_with(1, Some(10.0), None),
+                result_with(2, Some(20.0), None),
+                result_with(3, Some(30.0), None),
+                result_with(4, Some(40.0), None),
+            ],
+        };
+        assert_eq!(even.download_stats(), Some((10.0, 25.0, 40.0, 25.0)));
+    }
+
+    #[test]
+    fn test_stats_handles_ties_and_missing_measurements() {
+        let report = BenchmarkReport {
+            results: vec![
+                result_with(1, Some(50.0), None),
+                result_with(2, Some(50.0), None),
+                result_with(3, None, None),
+            ],
+        };
+        assert_eq!(report.download_stats(), Some((50.0, 50.0, 50.0, 50.0)));
+        assert_eq!(report.upload_stats(), None);
+    }
 }
\ No newline at end of file